@@ -0,0 +1,134 @@
+/// A simple rectangle used in the computation of the layout and to give widgets a hint about the
+/// area they are supposed to render to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn area(self) -> u16 {
+        self.width * self.height
+    }
+
+    pub fn left(self) -> u16 {
+        self.x
+    }
+
+    pub fn right(self) -> u16 {
+        self.x + self.width
+    }
+
+    pub fn top(self) -> u16 {
+        self.y
+    }
+
+    pub fn bottom(self) -> u16 {
+        self.y + self.height
+    }
+
+    /// Returns true if the given coordinate falls within this rect.
+    pub fn hit(self, x: u16, y: u16) -> bool {
+        x >= self.left() && x < self.right() && y >= self.top() && y < self.bottom()
+    }
+
+    /// Returns a new `Rect` shrunk symmetrically by `margin`, saturating at zero instead of
+    /// underflowing when the margin is larger than this rect.
+    pub fn inner(self, margin: Margin) -> Rect {
+        let doubled_horizontal = margin.horizontal.saturating_mul(2);
+        let doubled_vertical = margin.vertical.saturating_mul(2);
+        if self.width < doubled_horizontal || self.height < doubled_vertical {
+            Rect::default()
+        } else {
+            Rect {
+                x: self.x + margin.horizontal,
+                y: self.y + margin.vertical,
+                width: self.width - doubled_horizontal,
+                height: self.height - doubled_vertical,
+            }
+        }
+    }
+}
+
+/// Extra padding applied symmetrically on top of a `Rect`, e.g. to pad a `Block`'s inner area
+/// beyond its border insets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Margin {
+    pub horizontal: u16,
+    pub vertical: u16,
+}
+
+impl Margin {
+    pub fn new(horizontal: u16, vertical: u16) -> Margin {
+        Margin {
+            horizontal,
+            vertical,
+        }
+    }
+
+    pub fn horizontal(n: u16) -> Margin {
+        Margin {
+            horizontal: n,
+            vertical: 0,
+        }
+    }
+
+    pub fn vertical(n: u16) -> Margin {
+        Margin {
+            horizontal: 0,
+            vertical: n,
+        }
+    }
+}
+
+/// Alignment of text or titles within the area available to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// Computes the starting column of a line of the given width inside an area of
+/// `text_area_width` columns, according to `alignment`.
+pub(crate) fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
+    match alignment {
+        Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
+        Alignment::Right => text_area_width.saturating_sub(line_width),
+        Alignment::Left => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inner_shrinks_symmetrically_by_the_margin() {
+        let rect = Rect::new(0, 0, 10, 6);
+        assert_eq!(rect.inner(Margin::new(2, 1)), Rect::new(2, 1, 6, 4));
+    }
+
+    #[test]
+    fn inner_saturates_at_zero_instead_of_underflowing() {
+        let rect = Rect::new(0, 0, 3, 3);
+        assert_eq!(rect.inner(Margin::new(5, 5)), Rect::default());
+    }
+
+    #[test]
+    fn inner_saturates_when_only_one_axis_overflows() {
+        let rect = Rect::new(0, 0, 10, 3);
+        assert_eq!(rect.inner(Margin::new(2, 5)), Rect::default());
+    }
+}
@@ -31,6 +31,69 @@ pub mod line {
     pub const VERTICAL_RIGHT: &str = "├";
     pub const HORIZONTAL_DOWN: &str = "┬";
     pub const HORIZONTAL_UP: &str = "┴";
+
+    /// The set of glyphs needed to draw a box, grouped so a whole border style can be swapped
+    /// out at once.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Set {
+        pub vertical: &'static str,
+        pub horizontal: &'static str,
+        pub top_right: &'static str,
+        pub top_left: &'static str,
+        pub bottom_right: &'static str,
+        pub bottom_left: &'static str,
+        pub vertical_left: &'static str,
+        pub vertical_right: &'static str,
+        pub horizontal_down: &'static str,
+        pub horizontal_up: &'static str,
+    }
+
+    pub const NORMAL: Set = Set {
+        vertical: VERTICAL,
+        horizontal: HORIZONTAL,
+        top_right: TOP_RIGHT,
+        top_left: TOP_LEFT,
+        bottom_right: BOTTOM_RIGHT,
+        bottom_left: BOTTOM_LEFT,
+        vertical_left: VERTICAL_LEFT,
+        vertical_right: VERTICAL_RIGHT,
+        horizontal_down: HORIZONTAL_DOWN,
+        horizontal_up: HORIZONTAL_UP,
+    };
+
+    pub const ROUNDED: Set = Set {
+        top_right: rounded::TOP_RIGHT,
+        top_left: rounded::TOP_LEFT,
+        bottom_right: rounded::BOTTOM_RIGHT,
+        bottom_left: rounded::BOTTOM_LEFT,
+        ..NORMAL
+    };
+
+    pub const DOUBLE: Set = Set {
+        vertical: "║",
+        horizontal: "═",
+        top_right: "╗",
+        top_left: "╔",
+        bottom_right: "╝",
+        bottom_left: "╚",
+        vertical_left: "╣",
+        vertical_right: "╠",
+        horizontal_down: "╦",
+        horizontal_up: "╩",
+    };
+
+    pub const THICK: Set = Set {
+        vertical: "┃",
+        horizontal: "━",
+        top_right: "┓",
+        top_left: "┏",
+        bottom_right: "┛",
+        bottom_left: "┗",
+        vertical_left: "┫",
+        vertical_right: "┣",
+        horizontal_down: "┳",
+        horizontal_up: "┻",
+    };
 }
 
 pub mod rounded {
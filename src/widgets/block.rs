@@ -1,11 +1,36 @@
 use crate::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{get_line_offset, Alignment, Margin, Rect},
     style::Style,
     symbols::line,
-    widgets::{Borders, Widget},
+    widgets::{Borders, Text, Widget},
 };
 use sauron_vdom::{Attribute, Callback, Event};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The style of border glyphs a [`Block`] draws with.
+///
+/// Defaults to `Plain` so existing callers keep the glyphs they already had.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorderType {
+    Plain,
+    Rounded,
+    Double,
+    Thick,
+}
+
+impl BorderType {
+    /// Returns the [`line::Set`] of glyphs to use for this border type.
+    pub fn line_symbols(border_type: BorderType) -> line::Set {
+        match border_type {
+            BorderType::Plain => line::NORMAL,
+            BorderType::Rounded => line::ROUNDED,
+            BorderType::Double => line::DOUBLE,
+            BorderType::Thick => line::THICK,
+        }
+    }
+}
 
 /// Base widget to be used with all upper level ones. It may be used to display a box border around
 /// the widget and/or add a title.
@@ -13,11 +38,11 @@ use sauron_vdom::{Attribute, Callback, Event};
 /// # Examples
 ///
 /// ```
-/// # use itui::widgets::{Block, Borders};
+/// # use itui::widgets::{Block, Borders, Text};
 /// # use itui::style::{Style, Color};
 /// # fn main() {
 /// Block::default()
-///     .title("Block")
+///     .title(vec![Text::raw("Block")])
 ///     .title_style(Style::default().fg(Color::Red))
 ///     .borders(Borders::LEFT | Borders::RIGHT)
 ///     .border_style(Style::default().fg(Color::White))
@@ -26,16 +51,22 @@ use sauron_vdom::{Attribute, Callback, Event};
 /// ```
 #[derive(Clone)]
 pub struct Block<'a, MSG> {
-    /// Optional title place on the upper left of the block
-    title: Option<&'a str>,
-    /// Title style
+    /// Optional title, as a sequence of styled segments, placed on the top border
+    title: Vec<Text<'a>>,
+    /// Title style, applied to unstyled (`Text::Raw`) segments of the title
     title_style: Style,
+    /// Where the title is positioned along the top border
+    title_alignment: Alignment,
     /// Visible borders
     borders: Borders,
     /// Border style
     border_style: Style,
+    /// Border glyph style (plain, rounded, double, thick)
+    border_type: BorderType,
     /// Widget style
     style: Style,
+    /// Additional padding applied on top of the border insets when computing `inner()`
+    margin: Margin,
     /// area of the block,
     area: Rect,
     /// events attached to this block
@@ -45,11 +76,14 @@ pub struct Block<'a, MSG> {
 impl<'a, MSG> Default for Block<'a, MSG> {
     fn default() -> Self {
         Block {
-            title: None,
+            title: vec![],
             title_style: Default::default(),
+            title_alignment: Alignment::Left,
             borders: Borders::NONE,
             border_style: Default::default(),
+            border_type: BorderType::Plain,
             style: Default::default(),
+            margin: Margin::default(),
             area: Default::default(),
             events: vec![],
         }
@@ -60,8 +94,13 @@ impl<'a, MSG> Block<'a, MSG>
 where
     MSG: 'static,
 {
-    pub fn title(mut self, title: &'a str) -> Self {
-        self.title = Some(title);
+    pub fn title(mut self, title: Vec<Text<'a>>) -> Self {
+        self.title = title;
+        self
+    }
+
+    pub fn title_alignment(mut self, alignment: Alignment) -> Self {
+        self.title_alignment = alignment;
         self
     }
 
@@ -89,6 +128,17 @@ where
         self.borders = flag;
         self
     }
+
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    pub fn margin(mut self, margin: Margin) -> Self {
+        self.margin = margin;
+        self
+    }
+
     pub fn triggers_event(&self, event: &Event) -> Option<&Callback<Event, MSG>> {
         match event {
             Event::MouseEvent(me) => {
@@ -119,7 +169,7 @@ where
             inner.x += 1;
             inner.width -= 1;
         }
-        if self.borders.intersects(Borders::TOP) || self.title.is_some() {
+        if self.borders.intersects(Borders::TOP) || !self.title.is_empty() {
             inner.y += 1;
             inner.height -= 1;
         }
@@ -129,7 +179,7 @@ where
         if self.borders.intersects(Borders::BOTTOM) {
             inner.height -= 1;
         }
-        inner
+        inner.inner(self.margin)
     }
 }
 
@@ -145,18 +195,20 @@ impl<'a, MSG> Widget for Block<'a, MSG> {
 
         self.background(buf, self.style.bg);
 
+        let symbols = BorderType::line_symbols(self.border_type);
+
         // Sides
         if self.borders.intersects(Borders::LEFT) {
             for y in self.area.top()..self.area.bottom() {
                 buf.get_mut(self.area.left(), y)
-                    .set_symbol(line::VERTICAL)
+                    .set_symbol(symbols.vertical)
                     .set_style(self.border_style);
             }
         }
         if self.borders.intersects(Borders::TOP) {
             for x in self.area.left()..self.area.right() {
                 buf.get_mut(x, self.area.top())
-                    .set_symbol(line::HORIZONTAL)
+                    .set_symbol(symbols.horizontal)
                     .set_style(self.border_style);
             }
         }
@@ -164,7 +216,7 @@ impl<'a, MSG> Widget for Block<'a, MSG> {
             let x = self.area.right() - 1;
             for y in self.area.top()..self.area.bottom() {
                 buf.get_mut(x, y)
-                    .set_symbol(line::VERTICAL)
+                    .set_symbol(symbols.vertical)
                     .set_style(self.border_style);
             }
         }
@@ -172,7 +224,7 @@ impl<'a, MSG> Widget for Block<'a, MSG> {
             let y = self.area.bottom() - 1;
             for x in self.area.left()..self.area.right() {
                 buf.get_mut(x, y)
-                    .set_symbol(line::HORIZONTAL)
+                    .set_symbol(symbols.horizontal)
                     .set_style(self.border_style);
             }
         }
@@ -180,46 +232,84 @@ impl<'a, MSG> Widget for Block<'a, MSG> {
         // Corners
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
             buf.get_mut(self.area.left(), self.area.top())
-                .set_symbol(line::TOP_LEFT)
+                .set_symbol(symbols.top_left)
                 .set_style(self.border_style);
         }
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
             buf.get_mut(self.area.right() - 1, self.area.top())
-                .set_symbol(line::TOP_RIGHT)
+                .set_symbol(symbols.top_right)
                 .set_style(self.border_style);
         }
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
             buf.get_mut(self.area.left(), self.area.bottom() - 1)
-                .set_symbol(line::BOTTOM_LEFT)
+                .set_symbol(symbols.bottom_left)
                 .set_style(self.border_style);
         }
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
             buf.get_mut(self.area.right() - 1, self.area.bottom() - 1)
-                .set_symbol(line::BOTTOM_RIGHT)
+                .set_symbol(symbols.bottom_right)
                 .set_style(self.border_style);
         }
 
-        if self.area.width > 2 {
-            if let Some(title) = self.title {
-                let lx = if self.borders.intersects(Borders::LEFT) {
-                    1
-                } else {
-                    0
-                };
-                let rx = if self.borders.intersects(Borders::RIGHT) {
-                    1
-                } else {
-                    0
-                };
-                let width = self.area.width - lx - rx;
-                buf.set_stringn(
-                    self.area.left() + lx,
-                    self.area.top(),
-                    title,
-                    width as usize,
-                    self.title_style,
-                );
+        if self.area.width > 2 && !self.title.is_empty() {
+            let lx = if self.borders.intersects(Borders::LEFT) {
+                1
+            } else {
+                0
+            };
+            let rx = if self.borders.intersects(Borders::RIGHT) {
+                1
+            } else {
+                0
+            };
+            let available_width = self.area.width - lx - rx;
+
+            let segments: Vec<(&str, Style)> = self
+                .title
+                .iter()
+                .map(|t| match t {
+                    Text::Raw(ref d) => (d.as_ref(), self.title_style),
+                    Text::Styled(ref d, s) => (d.as_ref(), *s),
+                })
+                .collect();
+            let title_width: u16 = segments
+                .iter()
+                .map(|(d, _)| d.width() as u16)
+                .sum::<u16>()
+                .min(available_width);
+
+            let mut x = get_line_offset(title_width, available_width, self.title_alignment);
+            'segments: for (content, style) in segments {
+                for grapheme in UnicodeSegmentation::graphemes(content, true) {
+                    if x >= available_width {
+                        break 'segments;
+                    }
+                    buf.get_mut(self.area.left() + lx + x, self.area.top())
+                        .set_symbol(grapheme)
+                        .set_style(style);
+                    x += grapheme.width() as u16;
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_symbols_maps_each_border_type_to_its_glyph_set() {
+        assert_eq!(BorderType::line_symbols(BorderType::Plain), line::NORMAL);
+        assert_eq!(BorderType::line_symbols(BorderType::Rounded), line::ROUNDED);
+        assert_eq!(BorderType::line_symbols(BorderType::Double), line::DOUBLE);
+        assert_eq!(BorderType::line_symbols(BorderType::Thick), line::THICK);
+    }
+
+    #[test]
+    fn rounded_keeps_the_normal_straight_edges() {
+        let rounded = BorderType::line_symbols(BorderType::Rounded);
+        assert_eq!(rounded.vertical, line::NORMAL.vertical);
+        assert_eq!(rounded.horizontal, line::NORMAL.horizontal);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// A widget to clear a region, useful to render overlays and popups on top of existing content.
+///
+/// # Examples
+///
+/// ```
+/// # use itui::widgets::{Block, Borders, Clear};
+/// # use itui::layout::Rect;
+/// # fn main() {
+/// let popup_area = Rect::new(5, 5, 20, 5);
+/// Clear::default().area(popup_area);
+/// Block::default().title(vec![]).borders(Borders::ALL).area(popup_area);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Clear {
+    /// area to clear
+    area: Rect,
+}
+
+impl Clear {
+    pub fn area(mut self, area: Rect) -> Self {
+        self.area = area;
+        self
+    }
+}
+
+impl Widget for Clear {
+    fn get_area(&self) -> Rect {
+        self.area
+    }
+
+    fn draw(&mut self, buf: &mut Buffer) {
+        for x in self.area.left()..self.area.right() {
+            for y in self.area.top()..self.area.bottom() {
+                buf.get_mut(x, y).reset();
+            }
+        }
+    }
+}
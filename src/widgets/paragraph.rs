@@ -5,7 +5,7 @@ use unicode_width::UnicodeWidthStr;
 
 use crate::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
+    layout::{get_line_offset, Alignment, Rect},
     style::Style,
     widgets::{
         reflow::{LineComposer, LineTruncator, Styled, WordWrapper},
@@ -13,11 +13,26 @@ use crate::{
     },
 };
 
-fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
-    match alignment {
-        Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
-        Alignment::Right => text_area_width.saturating_sub(line_width),
-        Alignment::Left => 0,
+/// A scroll offset for a [`Paragraph`], in cells. `x` pans non-wrapping text horizontally and `y`
+/// skips leading lines, same as the original vertical-only `scroll`.
+///
+/// A bare `u16` still converts into one (as a `y` offset), so existing `.scroll(n)` call sites
+/// keep compiling; pass a `(x, y)` tuple to scroll both axes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollOffset {
+    pub x: u16,
+    pub y: u16,
+}
+
+impl From<u16> for ScrollOffset {
+    fn from(y: u16) -> Self {
+        ScrollOffset { x: 0, y }
+    }
+}
+
+impl From<(u16, u16)> for ScrollOffset {
+    fn from((x, y): (u16, u16)) -> Self {
+        ScrollOffset { x, y }
     }
 }
 
@@ -35,7 +50,7 @@ fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment)
 ///     Text::styled("Second line\n", Style::default().fg(Color::Red))
 /// ];
 /// Paragraph::new(text.iter())
-///     .block(Block::default().title("Paragraph").borders(Borders::ALL))
+///     .block(Block::default().title(vec![Text::raw("Paragraph")]).borders(Borders::ALL))
 ///     .style(Style::default().fg(Color::White).bg(Color::Black))
 ///     .alignment(Alignment::Center)
 ///     .wrap(true);
@@ -57,8 +72,8 @@ where
     text: T,
     /// Should we parse the text for embedded commands
     raw: bool,
-    /// Scroll
-    scroll: u16,
+    /// Scroll offset
+    scroll: ScrollOffset,
     /// Aligenment of the text
     alignment: Alignment,
     /// events attached to this block
@@ -77,7 +92,7 @@ where
             wrapping: false,
             raw: false,
             text,
-            scroll: 0,
+            scroll: ScrollOffset::default(),
             alignment: Alignment::Left,
             area: Default::default(),
             events: vec![],
@@ -104,8 +119,8 @@ where
         self
     }
 
-    pub fn scroll(mut self, offset: u16) -> Self {
-        self.scroll = offset;
+    pub fn scroll(mut self, offset: impl Into<ScrollOffset>) -> Self {
+        self.scroll = offset.into();
         self
     }
 
@@ -139,6 +154,29 @@ where
     }
 }
 
+/// Decides where (if at all) the next glyph of a composed line should be drawn, advancing `x`
+/// and `hscroll_remaining` in place. Glyphs starting within the scrolled-off `hscroll_remaining`
+/// budget, or past `viewport_width` once drawing resumes, are dropped by returning `None`.
+fn next_visible_column(
+    x: &mut u16,
+    hscroll_remaining: &mut u16,
+    symbol_width: u16,
+    viewport_width: u16,
+) -> Option<u16> {
+    if *hscroll_remaining > 0 {
+        // A glyph starting inside the scrolled-off budget is dropped, even when it is wider
+        // than what is left of the budget.
+        *hscroll_remaining = hscroll_remaining.saturating_sub(symbol_width);
+        return None;
+    }
+    if *x >= viewport_width {
+        return None;
+    }
+    let column = *x;
+    *x += symbol_width;
+    Some(column)
+}
+
 impl<'a, 't, 'b, T, MSG> Widget for Paragraph<'a, 't, T, MSG>
 where
     T: Iterator<Item = &'t Text<'t>>,
@@ -177,23 +215,74 @@ where
         let mut line_composer: Box<dyn LineComposer> = if self.wrapping {
             Box::new(WordWrapper::new(&mut styled, text_area.width))
         } else {
-            Box::new(LineTruncator::new(&mut styled, text_area.width))
+            // Compose past the viewport width so there's content to pan into via `scroll.x`.
+            Box::new(LineTruncator::new(&mut styled, text_area.width + self.scroll.x))
         };
         let mut y = 0;
         while let Some((current_line, current_line_width)) = line_composer.next_line() {
-            if y >= self.scroll {
+            if y >= self.scroll.y {
                 let mut x = get_line_offset(current_line_width, text_area.width, self.alignment);
+                // Horizontal scrolling only makes sense for non-wrapping, truncated lines.
+                let mut hscroll_remaining = if self.wrapping { 0 } else { self.scroll.x };
                 for Styled(symbol, style) in current_line {
-                    buf.get_mut(text_area.left() + x, text_area.top() + y - self.scroll)
-                        .set_symbol(symbol)
-                        .set_style(*style);
-                    x += symbol.width() as u16;
+                    let symbol_width = symbol.width() as u16;
+                    if let Some(column) =
+                        next_visible_column(&mut x, &mut hscroll_remaining, symbol_width, text_area.width)
+                    {
+                        buf.get_mut(text_area.left() + column, text_area.top() + y - self.scroll.y)
+                            .set_symbol(symbol)
+                            .set_style(*style);
+                    }
                 }
             }
             y += 1;
-            if y >= text_area.height + self.scroll {
+            if y >= text_area.height + self.scroll.y {
                 break;
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns_for(widths: &[u16], scroll_x: u16, viewport_width: u16) -> Vec<Option<u16>> {
+        let mut x = 0;
+        let mut hscroll_remaining = scroll_x;
+        widths
+            .iter()
+            .map(|&w| next_visible_column(&mut x, &mut hscroll_remaining, w, viewport_width))
+            .collect()
+    }
+
+    #[test]
+    fn no_scroll_draws_glyphs_left_to_right() {
+        assert_eq!(
+            columns_for(&[1, 1, 1], 0, 10),
+            vec![Some(0), Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn scroll_drops_glyphs_under_the_offset_and_shifts_the_rest_left() {
+        // Three single-width glyphs, scrolled 2 columns in: the first two are dropped and the
+        // third lands back at column 0.
+        assert_eq!(columns_for(&[1, 1, 1], 2, 10), vec![None, None, Some(0)]);
+    }
+
+    #[test]
+    fn scroll_can_split_a_wide_glyph() {
+        // A width-2 glyph only partially covered by the scroll offset is dropped entirely, and
+        // the next glyph starts drawing from column 0.
+        assert_eq!(columns_for(&[2, 1], 1, 10), vec![None, Some(0)]);
+    }
+
+    #[test]
+    fn glyphs_past_the_viewport_are_clipped() {
+        assert_eq!(
+            columns_for(&[1, 1, 1], 0, 2),
+            vec![Some(0), Some(1), None]
+        );
+    }
+}